@@ -0,0 +1,426 @@
+use std::collections::HashSet;
+
+use crate::pattern::{self, ParsedPattern, PatternError};
+use crate::rule::Rule;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CellState {
+    Alive,
+    Dead(u8),
+}
+
+impl Default for CellState {
+    fn default() -> Self {
+        CellState::Dead(u8::MAX)
+    }
+}
+
+#[derive(Default, Clone, Debug)]
+pub struct Cell {
+    pub current: CellState,
+    /// Holds the previous `current`, so renderers can tell which cells
+    /// actually changed this generation without rescanning neighbors.
+    pub next: CellState,
+}
+
+#[derive(Debug, Clone)]
+pub struct Board {
+    pub cells: Vec<Cell>,
+    pub width: usize,
+    pub height: usize,
+    rule: Rule,
+    /// Count of `Alive` neighbors per cell, kept in sync incrementally as
+    /// cells flip instead of being rescanned every generation.
+    neighbor_counts: Vec<u8>,
+    /// Indices that need their transition re-evaluated next step: cells
+    /// that flipped last step, and cells whose neighbor count changed
+    /// because a neighbor flipped.
+    dirty: HashSet<usize>,
+}
+
+impl Board {
+    pub fn from_shape(width: usize, height: usize) -> Self {
+        Board {
+            width,
+            height,
+            cells: vec![Cell::default(); width * height],
+            rule: Rule::default(),
+            neighbor_counts: vec![0; width * height],
+            dirty: HashSet::new(),
+        }
+    }
+
+    pub fn with_rule(mut self, rule: Rule) -> Self {
+        self.rule = rule;
+        self.rebuild_neighbor_bookkeeping();
+        self
+    }
+
+    pub fn randomize(mut self, probability: f64, generator: &mut dyn rand::RngCore) -> Self {
+        use rand::Rng;
+        for cell in self.cells.iter_mut() {
+            if generator.gen_bool(probability) {
+                cell.current = CellState::Alive;
+            }
+        }
+        self.rebuild_neighbor_bookkeeping();
+        self
+    }
+
+    /// Builds a board from the plaintext Life format: one line per row,
+    /// `*`/`O` for live cells and `.`/space for dead ones. The parsed
+    /// pattern is centered on a board of the given shape.
+    pub fn from_plaintext(width: usize, height: usize, text: &str) -> Result<Self, PatternError> {
+        let parsed = pattern::parse_plaintext(text)?;
+        Board::from_shape(width, height).with_pattern(parsed)
+    }
+
+    /// Builds a board from the run-length-encoded `.rle` Life format. If the
+    /// header specifies a `rule = ...` clause it is applied to the board,
+    /// otherwise `default_rule` is used (most shared `.rle` patterns omit the
+    /// clause and assume Conway's rule, but a caller resolving `--rule` wants
+    /// that honored instead). The parsed pattern is centered on a board of
+    /// the given shape.
+    pub fn from_rle(
+        width: usize,
+        height: usize,
+        text: &str,
+        default_rule: Rule,
+    ) -> Result<Self, PatternError> {
+        let parsed = pattern::parse_rle(text)?;
+        let rule = parsed.rule.unwrap_or(default_rule);
+        let board = Board::from_shape(width, height).with_pattern(parsed)?;
+        Ok(board.with_rule(rule))
+    }
+
+    fn with_pattern(mut self, parsed: ParsedPattern) -> Result<Self, PatternError> {
+        if parsed.width > self.width || parsed.height > self.height {
+            return Err(PatternError::TooLarge {
+                pattern_width: parsed.width,
+                pattern_height: parsed.height,
+                board_width: self.width,
+                board_height: self.height,
+            });
+        }
+
+        let x_offset = (self.width - parsed.width) / 2;
+        let y_offset = (self.height - parsed.height) / 2;
+
+        for (x, y) in parsed.live_cells {
+            let index = self.index_by_position((x + x_offset) as i32, (y + y_offset) as i32);
+            self.cells[index].current = CellState::Alive;
+        }
+
+        self.rebuild_neighbor_bookkeeping();
+        Ok(self)
+    }
+
+    /// Toggles a single cell between `Alive` and `Dead`, for editors that
+    /// let a user paint patterns by hand (mouse clicks, cursor movement).
+    pub fn flip_state(&mut self, x: i32, y: i32) {
+        let index = self.index_by_position(x, y);
+        let was_alive = self.cells[index].current == CellState::Alive;
+        self.cells[index].current = if was_alive {
+            CellState::Dead(1)
+        } else {
+            CellState::Alive
+        };
+        self.adjust_neighbor_counts(index, if was_alive { -1 } else { 1 });
+        self.mark_dirty_with_neighbors(index);
+    }
+
+    pub fn index_by_position(&self, x: i32, y: i32) -> usize {
+        let board_width = self.width as i32;
+        let board_height = self.height as i32;
+        (board_width * ((y + board_height) % board_height) + (x + board_width) % board_width)
+            as usize
+    }
+
+    fn position_by_index(&self, index: usize) -> (i32, i32) {
+        ((index % self.width) as i32, (index / self.width) as i32)
+    }
+
+    /// Rescans the whole board once to rebuild `neighbor_counts` and
+    /// `dirty` from scratch. Used after bulk mutations (randomizing,
+    /// loading a pattern, changing the rule) where many cells change at
+    /// once; incremental per-cell updates take over from there.
+    fn rebuild_neighbor_bookkeeping(&mut self) {
+        self.neighbor_counts = vec![0; self.cells.len()];
+        for index in 0..self.cells.len() {
+            if self.cells[index].current == CellState::Alive {
+                self.adjust_neighbor_counts(index, 1);
+            }
+        }
+        // Under a rule with `is_born(0)` (e.g. `B0/...`), every dead cell is
+        // a legal birth site regardless of its neighbor count, so the usual
+        // "alive or has live neighbors" filter would permanently exclude
+        // zero-neighbor dead cells from `dirty` and the board would never
+        // produce the births such a rule calls for.
+        self.dirty = if self.rule.is_born(0) {
+            (0..self.cells.len()).collect()
+        } else {
+            (0..self.cells.len())
+                .filter(|&index| {
+                    self.cells[index].current == CellState::Alive
+                        || self.neighbor_counts[index] > 0
+                })
+                .collect()
+        };
+    }
+
+    fn adjust_neighbor_counts(&mut self, index: usize, delta: i8) {
+        let (x, y) = self.position_by_index(index);
+        for x_shift in [-1, 0, 1] {
+            for y_shift in [-1, 0, 1] {
+                if x_shift == 0 && y_shift == 0 {
+                    continue;
+                }
+                let neighbor_index = self.index_by_position(x + x_shift, y + y_shift);
+                self.neighbor_counts[neighbor_index] =
+                    (self.neighbor_counts[neighbor_index] as i16 + delta as i16) as u8;
+            }
+        }
+    }
+
+    fn mark_dirty_with_neighbors(&mut self, index: usize) {
+        let (x, y) = self.position_by_index(index);
+        self.dirty.insert(index);
+        for x_shift in [-1, 0, 1] {
+            for y_shift in [-1, 0, 1] {
+                if x_shift == 0 && y_shift == 0 {
+                    continue;
+                }
+                self.dirty
+                    .insert(self.index_by_position(x + x_shift, y + y_shift));
+            }
+        }
+    }
+
+    fn next_state_for(current: CellState, alive_around: u8, rule: &Rule) -> CellState {
+        match current {
+            CellState::Alive => {
+                if rule.is_survivor(alive_around) {
+                    CellState::Alive
+                } else {
+                    // Death by {over,under}crowd
+                    CellState::Dead(1)
+                }
+            }
+            CellState::Dead(cycles) => {
+                if rule.is_born(alive_around) {
+                    CellState::Alive
+                } else {
+                    CellState::Dead(match cycles {
+                        u8::MAX => u8::MAX,
+                        _ => cycles + 1,
+                    })
+                }
+            }
+        }
+    }
+
+    // Only `dirty` cells -- ones whose neighbor count changed last step, or
+    // that themselves flipped -- are re-evaluated against the rule, so cost
+    // is proportional to the number of *active* cells rather than the
+    // whole board. Every dead cell still has its age counter bumped each
+    // generation (a single O(n) pass) so the terminal's fade-to-black
+    // rendering keeps working unchanged.
+    #[cfg(not(feature = "advanced_threading"))]
+    pub fn compute_one_step(&mut self) {
+        for cell in self.cells.iter_mut() {
+            cell.next = cell.current;
+        }
+
+        // Under `is_born(0)`, zero-neighbor dead cells are legal birth sites
+        // every step, not just the step after a neighbor flips -- dirty
+        // propagation via `mark_dirty_with_neighbors` can't express that, so
+        // fall back to evaluating the whole board each generation.
+        if self.rule.is_born(0) {
+            self.dirty = (0..self.cells.len()).collect();
+        }
+
+        let dirty = std::mem::take(&mut self.dirty);
+        let mut flips = Vec::new();
+        for index in dirty {
+            let current = self.cells[index].current;
+            let alive_around = self.neighbor_counts[index];
+            let next = Self::next_state_for(current, alive_around, &self.rule);
+            let flipped = matches!(next, CellState::Alive) != matches!(current, CellState::Alive);
+            if flipped {
+                flips.push(index);
+            }
+        }
+
+        for cell in self.cells.iter_mut() {
+            if let CellState::Dead(cycles) = cell.current {
+                if cycles != u8::MAX {
+                    cell.current = CellState::Dead(cycles + 1);
+                }
+            }
+        }
+
+        for index in flips {
+            let was_alive = self.cells[index].current == CellState::Alive;
+            self.cells[index].current = if was_alive {
+                CellState::Dead(1)
+            } else {
+                CellState::Alive
+            };
+            self.adjust_neighbor_counts(index, if was_alive { -1 } else { 1 });
+            self.mark_dirty_with_neighbors(index);
+        }
+    }
+
+    // With the `advanced_threading` feature, the step is split into a read
+    // phase and a write phase so that row bands can be processed in
+    // parallel: every worker reads neighbor `current` states from the
+    // shared immutable view, but only ever writes into its own disjoint
+    // band of `next` states.
+    //
+    // This path is a separate tradeoff from the default `dirty`-tracking
+    // one above, not a parallel version of it: it recomputes every cell
+    // every step (cloning the whole board to get an immutable read view)
+    // and pays a full `rebuild_neighbor_bookkeeping` at the end of each
+    // step. The two optimizations are mutually exclusive -- `advanced_threading`
+    // trades the incremental path's per-step savings for parallelism across
+    // the whole board, which wins when most of the board is active and
+    // loses when it's mostly quiescent.
+    #[cfg(feature = "advanced_threading")]
+    pub fn compute_one_step(&mut self) {
+        use rayon::prelude::*;
+
+        let width = self.width;
+        let height = self.height;
+        let rule = self.rule;
+        let current_cells: Vec<Cell> = self.cells.clone();
+
+        let count_alive_around = |x: i32, y: i32| -> u8 {
+            let mut counter = 0;
+            for x_shift in [-1, 0, 1] {
+                for y_shift in [-1, 0, 1] {
+                    if x_shift == 0 && y_shift == 0 {
+                        continue;
+                    }
+                    let board_width = width as i32;
+                    let board_height = height as i32;
+                    let index = (board_width * ((y + y_shift + board_height) % board_height)
+                        + (x + x_shift + board_width) % board_width)
+                        as usize;
+                    if current_cells[index].current == CellState::Alive {
+                        counter += 1;
+                    }
+                }
+            }
+            counter
+        };
+
+        self.cells
+            .par_chunks_mut(width)
+            .enumerate()
+            .for_each(|(y_pos, row)| {
+                for (x_pos, cell) in row.iter_mut().enumerate() {
+                    let alive_around = count_alive_around(x_pos as i32, y_pos as i32);
+                    cell.next = Self::next_state_for(cell.current, alive_around, &rule);
+                }
+            });
+
+        // Swap the new and the old states
+        for cell in self.cells.iter_mut() {
+            std::mem::swap(&mut cell.current, &mut cell.next);
+        }
+        // The parallel path recomputes every cell's neighbors from scratch
+        // rather than tracking a dirty set, so `neighbor_counts` needs a
+        // full rebuild to stay correct for any interleaved `flip_state` call.
+        self.rebuild_neighbor_bookkeeping();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rule::Rule;
+
+    fn alive_vec(board: &Board) -> Vec<bool> {
+        board
+            .cells
+            .iter()
+            .map(|cell| cell.current == CellState::Alive)
+            .collect()
+    }
+
+    fn set_alive(board: &mut Board, coords: &[(i32, i32)]) {
+        for &(x, y) in coords {
+            let index = board.index_by_position(x, y);
+            board.cells[index].current = CellState::Alive;
+        }
+        board.rebuild_neighbor_bookkeeping();
+    }
+
+    /// Naive reference: recomputes every cell's next state from scratch
+    /// against a toroidal board, with no dirty tracking at all.
+    fn brute_force_step(alive: &[bool], width: usize, height: usize, rule: &Rule) -> Vec<bool> {
+        let index = |x: i32, y: i32| -> usize {
+            let board_width = width as i32;
+            let board_height = height as i32;
+            (board_width * ((y + board_height) % board_height) + (x + board_width) % board_width)
+                as usize
+        };
+
+        (0..height as i32)
+            .flat_map(|y| (0..width as i32).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                let mut alive_around = 0;
+                for x_shift in [-1, 0, 1] {
+                    for y_shift in [-1, 0, 1] {
+                        if x_shift == 0 && y_shift == 0 {
+                            continue;
+                        }
+                        if alive[index(x + x_shift, y + y_shift)] {
+                            alive_around += 1;
+                        }
+                    }
+                }
+                if alive[index(x, y)] {
+                    rule.is_survivor(alive_around)
+                } else {
+                    rule.is_born(alive_around)
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn matches_brute_force_over_several_generations_under_conway() {
+        let width = 8;
+        let height = 8;
+        let rule = Rule::conway();
+        let mut board = Board::from_shape(width, height).with_rule(rule);
+        set_alive(&mut board, &[(3, 2), (4, 3), (2, 4), (3, 4), (4, 4)]); // glider
+
+        let mut reference = alive_vec(&board);
+        for _ in 0..6 {
+            board.compute_one_step();
+            reference = brute_force_step(&reference, width, height, &rule);
+            assert_eq!(alive_vec(&board), reference);
+        }
+    }
+
+    // Regression test: under a rule where `is_born(0)` holds, a dead cell
+    // with zero live neighbors is still a legal birth site every
+    // generation. The dirty-set optimization must not silently exclude it.
+    #[test]
+    fn matches_brute_force_over_several_generations_under_b0_rule() {
+        let width = 6;
+        let height = 6;
+        let rule: Rule = "B0/S8".parse().unwrap();
+        let mut board = Board::from_shape(width, height).with_rule(rule);
+        set_alive(&mut board, &[(0, 0)]);
+
+        let mut reference = alive_vec(&board);
+        for _ in 0..4 {
+            board.compute_one_step();
+            reference = brute_force_step(&reference, width, height, &rule);
+            assert_eq!(alive_vec(&board), reference);
+        }
+    }
+}
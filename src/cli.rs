@@ -0,0 +1,81 @@
+//! Command-line configuration, parsed with `clap`.
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use clap::Parser;
+
+use crate::rule::Rule;
+
+#[derive(Parser, Debug)]
+#[command(author, version, about = "Conway's Game of Life, and relatives")]
+pub struct Cli {
+    /// Board width in cells. Defaults to the terminal width.
+    #[arg(long)]
+    pub width: Option<u16>,
+
+    /// Board height in cells. Defaults to the terminal height.
+    #[arg(long)]
+    pub height: Option<u16>,
+
+    /// Probability that a cell starts alive when randomizing. Not used for the
+    /// starting board if `--pattern` is set, but still used by reseeding.
+    #[arg(long, default_value_t = 0.1)]
+    pub probability: f64,
+
+    /// Life-like rule in B/S notation, e.g. `B3/S23` (Conway) or `B36/S23` (HighLife).
+    #[arg(long, default_value = "B3/S23")]
+    pub rule: String,
+
+    /// Frames per second. Mutually exclusive with `--step-ms`.
+    #[arg(long, conflicts_with = "step_ms")]
+    pub fps: Option<u64>,
+
+    /// Milliseconds to sleep between generations. Mutually exclusive with `--fps`.
+    #[arg(long)]
+    pub step_ms: Option<u64>,
+
+    /// Seed the random number generator, for reproducible soups.
+    #[arg(long)]
+    pub seed: Option<u64>,
+
+    /// Load a seed pattern from a plaintext or `.rle` file instead of randomizing.
+    #[arg(long)]
+    pub pattern: Option<PathBuf>,
+
+    /// Run on the sparse, unbounded board instead of the toroidal one, viewing
+    /// it through a fixed-size viewport centered on the origin.
+    #[arg(long)]
+    pub infinite: bool,
+}
+
+impl Cli {
+    /// Parses `--rule`, exiting with a usage error if it isn't valid B/S notation.
+    pub fn parsed_rule(&self) -> Rule {
+        self.rule.parse().unwrap_or_else(|error| {
+            eprintln!("error: {error}");
+            std::process::exit(1);
+        })
+    }
+
+    /// Validates `--probability`, exiting with a usage error if it's outside `0.0..=1.0`
+    /// (the range `rand::Rng::gen_bool` accepts).
+    pub fn validated_probability(&self) -> f64 {
+        if !(0.0..=1.0).contains(&self.probability) {
+            eprintln!(
+                "error: --probability must be between 0.0 and 1.0, got {}",
+                self.probability
+            );
+            std::process::exit(1);
+        }
+        self.probability
+    }
+
+    pub fn step_duration(&self) -> Duration {
+        match (self.fps, self.step_ms) {
+            (_, Some(step_ms)) => Duration::from_millis(step_ms),
+            (Some(fps), None) => Duration::from_millis(1000 / fps.max(1)),
+            (None, None) => Duration::from_millis(10),
+        }
+    }
+}
@@ -0,0 +1,22 @@
+//! The age-to-color ramp shared by every renderer: a live cell is plain
+//! white, and a dead cell fades from a dim blue-violet down to black as it
+//! spends more generations dead. Both the `termion` terminal path and the
+//! `pixels`-based GUI map [`CellState`] through the same function so a
+//! pattern looks identical regardless of which frontend is driving it.
+
+use crate::board::CellState;
+
+pub fn cell_color(state: CellState) -> (u8, u8, u8) {
+    match state {
+        CellState::Alive => (u8::MAX, u8::MAX, u8::MAX),
+        CellState::Dead(cycles) => {
+            let intencity_multiplier: u16 = 20;
+            let intencity = if cycles as u16 * intencity_multiplier > u8::MAX as u16 {
+                0
+            } else {
+                u8::MAX - cycles * intencity_multiplier as u8
+            };
+            (intencity / 2, intencity / 5, intencity)
+        }
+    }
+}
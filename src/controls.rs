@@ -0,0 +1,77 @@
+//! Frontend-agnostic input semantics.
+//!
+//! Both the terminal loop (via `termion`) and the optional GUI (via
+//! `winit`, behind the `gui` feature) translate their raw input events into
+//! this module's [`Command`] so the two frontends agree on what "pause",
+//! "step", "speed up" and so on actually mean. The `termion` mapping lives
+//! here; the GUI maps `winit` keys to [`Command`] in `gui.rs`.
+
+#[cfg(not(feature = "gui"))]
+use termion::event::{Event, Key, MouseButton, MouseEvent};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    TogglePause,
+    Step,
+    SpeedUp,
+    SpeedDown,
+    Reseed,
+    Clear,
+    ToggleCell(i32, i32),
+    /// Rewinds one generation. Only meaningful on `InfiniteBoard`, which
+    /// keeps step-back history; the toroidal `Board` ignores it. Only the
+    /// terminal frontend exposes `--infinite`, so this is never constructed
+    /// in a `gui`-feature build.
+    #[cfg_attr(feature = "gui", allow(dead_code))]
+    StepBack,
+    Quit,
+}
+
+/// Maps a `termion` input event to an application [`Command`], or `None`
+/// if the event has no meaning to this program. Unused (and uncompiled)
+/// under the `gui` feature, which maps `winit` events instead.
+#[cfg(not(feature = "gui"))]
+pub fn command_for_event(event: Event) -> Option<Command> {
+    match event {
+        Event::Key(Key::Char(' ')) => Some(Command::TogglePause),
+        Event::Key(Key::Char('s')) => Some(Command::Step),
+        Event::Key(Key::Char('+')) => Some(Command::SpeedUp),
+        Event::Key(Key::Char('-')) => Some(Command::SpeedDown),
+        Event::Key(Key::Char('r')) => Some(Command::Reseed),
+        Event::Key(Key::Char('c')) => Some(Command::Clear),
+        Event::Key(Key::Char('b')) => Some(Command::StepBack),
+        Event::Key(Key::Char('q')) | Event::Key(Key::Esc) | Event::Key(Key::Ctrl('c')) => {
+            Some(Command::Quit)
+        }
+        Event::Mouse(MouseEvent::Press(MouseButton::Left, x, y)) => {
+            Some(Command::ToggleCell(x as i32 - 1, y as i32 - 1))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(all(test, not(feature = "gui")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_space_to_pause() {
+        assert_eq!(
+            command_for_event(Event::Key(Key::Char(' '))),
+            Some(Command::TogglePause)
+        );
+    }
+
+    #[test]
+    fn maps_left_click_to_toggle_cell() {
+        assert_eq!(
+            command_for_event(Event::Mouse(MouseEvent::Press(MouseButton::Left, 5, 3))),
+            Some(Command::ToggleCell(4, 2))
+        );
+    }
+
+    #[test]
+    fn ignores_unmapped_events() {
+        assert_eq!(command_for_event(Event::Key(Key::Char('z'))), None);
+    }
+}
@@ -0,0 +1,157 @@
+//! Windowed GPU frontend, behind the `gui` feature: renders the board as a
+//! `pixels` framebuffer inside a `winit` window instead of drawing it with
+//! terminal escape codes. Cell ages are mapped through the same
+//! [`crate::color::cell_color`] ramp the terminal path uses, so a pattern
+//! looks the same on either frontend.
+
+use pixels::{Pixels, SurfaceTexture};
+use rand::RngCore;
+use winit::dpi::LogicalSize;
+use winit::event::{ElementState, Event, MouseButton, VirtualKeyCode, WindowEvent};
+use winit::event_loop::{ControlFlow, EventLoop};
+use winit::window::WindowBuilder;
+
+use crate::board::Board;
+use crate::color::cell_color;
+use crate::controls::Command;
+use crate::rule::Rule;
+
+fn command_for_key(key: VirtualKeyCode) -> Option<Command> {
+    match key {
+        VirtualKeyCode::Space => Some(Command::TogglePause),
+        VirtualKeyCode::S => Some(Command::Step),
+        VirtualKeyCode::Equals | VirtualKeyCode::Plus => Some(Command::SpeedUp),
+        VirtualKeyCode::Minus => Some(Command::SpeedDown),
+        VirtualKeyCode::R => Some(Command::Reseed),
+        VirtualKeyCode::C => Some(Command::Clear),
+        VirtualKeyCode::Q | VirtualKeyCode::Escape => Some(Command::Quit),
+        _ => None,
+    }
+}
+
+/// Applies one mapped [`Command`] to the running GUI state; shared between
+/// the keyboard and mouse event handlers so both go through the same
+/// frontend-agnostic semantics as the terminal loop.
+#[allow(clippy::too_many_arguments)]
+fn handle_command(
+    command: Option<Command>,
+    board: &mut Board,
+    paused: &mut bool,
+    step_ms: &mut u64,
+    rule: Rule,
+    probability: f64,
+    generator: &mut dyn RngCore,
+    control_flow: &mut ControlFlow,
+) {
+    match command {
+        Some(Command::Quit) => *control_flow = ControlFlow::Exit,
+        Some(Command::TogglePause) => *paused = !*paused,
+        Some(Command::Step) => {
+            if *paused {
+                board.compute_one_step();
+            }
+        }
+        Some(Command::SpeedUp) => *step_ms = step_ms.saturating_sub(5).max(1),
+        Some(Command::SpeedDown) => *step_ms += 5,
+        Some(Command::Reseed) => {
+            *board = Board::from_shape(board.width, board.height)
+                .with_rule(rule)
+                .randomize(probability, generator);
+        }
+        Some(Command::Clear) => {
+            *board = Board::from_shape(board.width, board.height).with_rule(rule);
+        }
+        Some(Command::ToggleCell(x, y)) => board.flip_state(x, y),
+        Some(Command::StepBack) | None => {}
+    }
+}
+
+/// Runs the windowed editor until the user closes the window or quits,
+/// rendering `board` at one pixel per cell. `rule` and `probability` are the
+/// resolved `--rule`/`--probability` values, reused by `Reseed` so the GUI
+/// frontend matches the terminal one; `generator` is the CLI-seeded RNG.
+pub fn run(
+    mut board: Board,
+    mut step_ms: u64,
+    rule: Rule,
+    probability: f64,
+    mut generator: Box<dyn RngCore>,
+) -> ! {
+    let event_loop = EventLoop::new();
+    let window_size = LogicalSize::new(board.width as f64, board.height as f64);
+    let window = WindowBuilder::new()
+        .with_title("gol-rs")
+        .with_inner_size(window_size)
+        .build(&event_loop)
+        .unwrap();
+
+    let surface_texture = SurfaceTexture::new(board.width as u32, board.height as u32, &window);
+    let mut pixels =
+        Pixels::new(board.width as u32, board.height as u32, surface_texture).unwrap();
+
+    let mut paused = false;
+    let mut cursor = (0i32, 0i32);
+
+    event_loop.run(move |event, _, control_flow| {
+        *control_flow = ControlFlow::WaitUntil(
+            std::time::Instant::now() + std::time::Duration::from_millis(step_ms),
+        );
+
+        match event {
+            Event::WindowEvent { event, .. } => match event {
+                WindowEvent::CloseRequested => *control_flow = ControlFlow::Exit,
+                WindowEvent::CursorMoved { position, .. } => {
+                    cursor = (position.x as i32, position.y as i32);
+                }
+                WindowEvent::MouseInput {
+                    state: ElementState::Pressed,
+                    button: MouseButton::Left,
+                    ..
+                } => {
+                    handle_command(
+                        Some(Command::ToggleCell(cursor.0, cursor.1)),
+                        &mut board,
+                        &mut paused,
+                        &mut step_ms,
+                        rule,
+                        probability,
+                        &mut *generator,
+                        control_flow,
+                    );
+                }
+                WindowEvent::KeyboardInput { input, .. } => {
+                    if input.state != ElementState::Pressed {
+                        return;
+                    }
+                    let Some(key) = input.virtual_keycode else {
+                        return;
+                    };
+                    handle_command(
+                        command_for_key(key),
+                        &mut board,
+                        &mut paused,
+                        &mut step_ms,
+                        rule,
+                        probability,
+                        &mut *generator,
+                        control_flow,
+                    );
+                }
+                _ => {}
+            },
+            Event::MainEventsCleared => {
+                if !paused {
+                    board.compute_one_step();
+                }
+                for (index, cell) in board.cells.iter().enumerate() {
+                    let (r, g, b) = cell_color(cell.current);
+                    let frame_index = index * 4;
+                    let frame = pixels.frame_mut();
+                    frame[frame_index..frame_index + 4].copy_from_slice(&[r, g, b, 0xff]);
+                }
+                pixels.render().unwrap();
+            }
+            _ => {}
+        }
+    });
+}
@@ -0,0 +1,206 @@
+//! A sparse, unbounded alternative to [`crate::board::Board`].
+//!
+//! Instead of a dense `Vec<Cell>` over a fixed, toroidal shape, only live
+//! cell coordinates are stored. Each generation is computed by tallying
+//! neighbor counts over the neighborhoods of currently live cells and
+//! applying the rule only to the coordinates that tally touches (plus the
+//! live cells themselves) -- there is no wraparound, and the universe can
+//! grow (or a glider gun can fire) without bound.
+
+use std::collections::{HashMap, VecDeque};
+
+use fxhash::FxHashSet;
+
+use crate::pattern::ParsedPattern;
+use crate::rule::Rule;
+
+type Coord = (i64, i64);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InfiniteBoardError {
+    /// Returned by [`InfiniteBoard::step_backward`] once the history ring
+    /// buffer has been exhausted.
+    NoPreviousTurn,
+}
+
+impl std::fmt::Display for InfiniteBoardError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InfiniteBoardError::NoPreviousTurn => write!(f, "no previous turn"),
+        }
+    }
+}
+
+impl std::error::Error for InfiniteBoardError {}
+
+#[derive(Debug, Clone)]
+pub struct InfiniteBoard {
+    initial_state: FxHashSet<Coord>,
+    live_cells: FxHashSet<Coord>,
+    history: VecDeque<FxHashSet<Coord>>,
+    history_capacity: usize,
+    rule: Rule,
+}
+
+impl InfiniteBoard {
+    const DEFAULT_HISTORY_CAPACITY: usize = 64;
+
+    pub fn from_live_cells<I: IntoIterator<Item = Coord>>(live_cells: I) -> Self {
+        let live_cells: FxHashSet<Coord> = live_cells.into_iter().collect();
+        InfiniteBoard {
+            initial_state: live_cells.clone(),
+            live_cells,
+            history: VecDeque::new(),
+            history_capacity: Self::DEFAULT_HISTORY_CAPACITY,
+            rule: Rule::default(),
+        }
+    }
+
+    pub fn with_rule(mut self, rule: Rule) -> Self {
+        self.rule = rule;
+        self
+    }
+
+    /// Builds a board from a pattern parsed by [`crate::pattern`], centered
+    /// on the origin instead of on a fixed-size board. Applies the file's
+    /// own rule if it specified one (only `.rle` headers carry one),
+    /// otherwise falls back to `default_rule`.
+    pub fn from_parsed_pattern(parsed: ParsedPattern, default_rule: Rule) -> Self {
+        let x_offset = parsed.width as i64 / 2;
+        let y_offset = parsed.height as i64 / 2;
+        let live_cells = parsed
+            .live_cells
+            .into_iter()
+            .map(|(x, y)| (x as i64 - x_offset, y as i64 - y_offset));
+
+        let rule = parsed.rule.unwrap_or(default_rule);
+        InfiniteBoard::from_live_cells(live_cells).with_rule(rule)
+    }
+
+    /// Sprinkles live cells at `probability` over a `width`x`height` box
+    /// centered on the origin, mirroring [`crate::board::Board::randomize`]
+    /// for the sparse backend.
+    pub fn randomize(
+        width: usize,
+        height: usize,
+        probability: f64,
+        generator: &mut dyn rand::RngCore,
+    ) -> Self {
+        use rand::Rng;
+
+        let x_offset = width as i64 / 2;
+        let y_offset = height as i64 / 2;
+        let mut live_cells = FxHashSet::default();
+        for y in 0..height as i64 {
+            for x in 0..width as i64 {
+                if generator.gen_bool(probability) {
+                    live_cells.insert((x - x_offset, y - y_offset));
+                }
+            }
+        }
+
+        InfiniteBoard::from_live_cells(live_cells)
+    }
+
+    pub fn live_cells(&self) -> &FxHashSet<Coord> {
+        &self.live_cells
+    }
+
+    /// Restores the board to the pattern it was constructed with, clearing
+    /// the step-back history.
+    pub fn reset(&mut self) {
+        self.live_cells = self.initial_state.clone();
+        self.history.clear();
+    }
+
+    pub fn step_forward(&mut self) {
+        self.history.push_back(self.live_cells.clone());
+        if self.history.len() > self.history_capacity {
+            self.history.pop_front();
+        }
+
+        let mut neighbor_counts: HashMap<Coord, u8> = HashMap::default();
+        for &(x, y) in &self.live_cells {
+            for x_shift in [-1, 0, 1] {
+                for y_shift in [-1, 0, 1] {
+                    if x_shift == 0 && y_shift == 0 {
+                        continue;
+                    }
+                    *neighbor_counts.entry((x + x_shift, y + y_shift)).or_insert(0) += 1;
+                }
+            }
+        }
+
+        let mut candidates: FxHashSet<Coord> = neighbor_counts.keys().copied().collect();
+        candidates.extend(self.live_cells.iter().copied());
+
+        self.live_cells = candidates
+            .into_iter()
+            .filter(|coord| {
+                let alive_around = neighbor_counts.get(coord).copied().unwrap_or(0);
+                if self.live_cells.contains(coord) {
+                    self.rule.is_survivor(alive_around)
+                } else {
+                    self.rule.is_born(alive_around)
+                }
+            })
+            .collect();
+    }
+
+    /// Rewinds to the previous generation, or fails if the history ring
+    /// buffer has no prior generation recorded.
+    pub fn step_backward(&mut self) -> Result<(), InfiniteBoardError> {
+        match self.history.pop_back() {
+            Some(previous) => {
+                self.live_cells = previous;
+                Ok(())
+            }
+            None => Err(InfiniteBoardError::NoPreviousTurn),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blinker() -> InfiniteBoard {
+        InfiniteBoard::from_live_cells([(0, 0), (1, 0), (2, 0)])
+    }
+
+    #[test]
+    fn blinker_oscillates() {
+        let mut board = blinker();
+        board.step_forward();
+        let expected: FxHashSet<Coord> = [(1, -1), (1, 0), (1, 1)].into_iter().collect();
+        assert_eq!(*board.live_cells(), expected);
+    }
+
+    #[test]
+    fn step_backward_restores_previous_generation() {
+        let mut board = blinker();
+        let before: FxHashSet<Coord> = board.live_cells().clone();
+        board.step_forward();
+        board.step_backward().unwrap();
+        assert_eq!(*board.live_cells(), before);
+    }
+
+    #[test]
+    fn step_backward_errors_once_history_is_exhausted() {
+        let mut board = blinker();
+        assert_eq!(
+            board.step_backward().unwrap_err(),
+            InfiniteBoardError::NoPreviousTurn
+        );
+    }
+
+    #[test]
+    fn reset_restores_initial_state_and_clears_history() {
+        let mut board = blinker();
+        let initial: FxHashSet<Coord> = board.live_cells().clone();
+        board.step_forward();
+        board.reset();
+        assert_eq!(*board.live_cells(), initial);
+        assert_eq!(board.step_backward().unwrap_err(), InfiniteBoardError::NoPreviousTurn);
+    }
+}
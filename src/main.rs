@@ -1,130 +1,151 @@
-use rand;
+use rand::{RngCore, SeedableRng};
+#[cfg(not(feature = "gui"))]
 use std::io::Write;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-enum CellState {
-    Alive,
-    Dead(u8),
-}
+mod board;
+mod cli;
+mod color;
+mod controls;
+#[cfg(feature = "gui")]
+mod gui;
+#[cfg(not(feature = "gui"))]
+mod infinite;
+mod pattern;
+mod rule;
+
+use board::Board;
+use cli::Cli;
+#[cfg(not(feature = "gui"))]
+use color::cell_color;
+#[cfg(not(feature = "gui"))]
+use controls::Command;
+#[cfg(not(feature = "gui"))]
+use infinite::InfiniteBoard;
 
-impl Default for CellState {
-    fn default() -> Self {
-        CellState::Dead(u8::MAX)
+fn rng_for(cli: &Cli) -> Box<dyn RngCore> {
+    match cli.seed {
+        Some(seed) => Box::new(rand::rngs::StdRng::seed_from_u64(seed)),
+        None => Box::new(rand::thread_rng()),
     }
 }
 
-#[derive(Default, Clone, Debug)]
-struct Cell {
-    current: CellState,
-    next: CellState,
+fn read_pattern_file(path: &std::path::Path) -> (String, bool) {
+    let text = std::fs::read_to_string(path).unwrap_or_else(|error| {
+        eprintln!("error reading {}: {error}", path.display());
+        std::process::exit(1);
+    });
+    let is_rle = path.extension().and_then(|extension| extension.to_str()) == Some("rle");
+    (text, is_rle)
+}
+
+/// Builds the starting board from CLI options: either a pattern loaded from
+/// `--pattern` (guessing plaintext vs. `.rle` by file extension), or a
+/// random soup seeded with `--probability` (and `--seed`, if given).
+fn initial_board(cli: &Cli, width: usize, height: usize, generator: &mut dyn RngCore) -> Board {
+    let Some(path) = &cli.pattern else {
+        return Board::from_shape(width, height)
+            .with_rule(cli.parsed_rule())
+            .randomize(cli.validated_probability(), generator);
+    };
+
+    let (text, is_rle) = read_pattern_file(path);
+    let board = if is_rle {
+        Board::from_rle(width, height, &text, cli.parsed_rule())
+    } else {
+        Board::from_plaintext(width, height, &text).map(|board| board.with_rule(cli.parsed_rule()))
+    };
+
+    board.unwrap_or_else(|error| {
+        eprintln!("error loading pattern {}: {error}", path.display());
+        std::process::exit(1);
+    })
 }
 
-#[derive(Debug, Clone)]
-struct Board {
-    cells: Vec<Cell>,
+/// Builds the starting sparse board the same way [`initial_board`] does for
+/// the dense one, centered on the origin instead of a fixed top-left corner.
+#[cfg(not(feature = "gui"))]
+fn initial_infinite_board(
+    cli: &Cli,
     width: usize,
     height: usize,
+    generator: &mut dyn RngCore,
+) -> InfiniteBoard {
+    let Some(path) = &cli.pattern else {
+        return InfiniteBoard::randomize(width, height, cli.validated_probability(), generator)
+            .with_rule(cli.parsed_rule());
+    };
+
+    let (text, is_rle) = read_pattern_file(path);
+    let parsed = if is_rle {
+        pattern::parse_rle(&text)
+    } else {
+        pattern::parse_plaintext(&text)
+    };
+
+    let parsed = parsed.unwrap_or_else(|error| {
+        eprintln!("error loading pattern {}: {error}", path.display());
+        std::process::exit(1);
+    });
+
+    InfiniteBoard::from_parsed_pattern(parsed, cli.parsed_rule())
 }
 
-impl Board {
-    pub fn from_shape(width: usize, height: usize) -> Self {
-        Board {
-            width,
-            height,
-            cells: vec![Cell::default(); width * height],
-        }
-    }
+#[cfg(feature = "gui")]
+fn main() {
+    use clap::Parser;
 
-    pub fn randomize<T>(mut self, probability: f64, generator: &mut T) -> Self
-    where
-        T: rand::Rng,
-    {
-        for cell in self.cells.iter_mut() {
-            if generator.gen_bool(probability) {
-                cell.current = CellState::Alive;
-            }
-        }
-        self
+    let cli = Cli::parse();
+    if cli.infinite {
+        eprintln!("error: --infinite is only supported by the terminal frontend, not --features gui");
+        std::process::exit(1);
     }
 
-    pub fn index_by_position(&self, x: i32, y: i32) -> usize {
-        let board_width = self.width as i32;
-        let board_height = self.height as i32;
-        (board_width * ((y + board_height) % board_height) + (x + board_width) % board_width)
-            as usize
-    }
+    let (terminal_width, terminal_height) = termion::terminal_size().unwrap();
+    let width = cli.width.unwrap_or(terminal_width) as usize;
+    let height = cli.height.unwrap_or(terminal_height) as usize;
 
-    fn count_cells_around_position(&self, x: i32, y: i32, what_state: CellState) -> u8 {
-        let mut counter = 0;
+    let mut generator = rng_for(&cli);
+    let board = initial_board(&cli, width, height, &mut *generator);
+    gui::run(
+        board,
+        cli.step_duration().as_millis() as u64,
+        cli.parsed_rule(),
+        cli.validated_probability(),
+        generator,
+    );
+}
 
-        for x_shift in [-1, 0, 1] {
-            for y_shift in [-1, 0, 1] {
-                if x_shift == 0 && y_shift == 0 {
-                    continue;
-                }
-                if self
-                    .cells
-                    .get(self.index_by_position(x + x_shift, y + y_shift))
-                    .unwrap()
-                    .current
-                    == what_state
-                {
-                    counter += 1;
-                }
-            }
-        }
+#[cfg(not(feature = "gui"))]
+fn main() {
+    use clap::Parser;
 
-        counter
-    }
+    let cli = Cli::parse();
+    let (terminal_width, terminal_height) = termion::terminal_size().unwrap();
+    let width = cli.width.unwrap_or(terminal_width) as usize;
+    let height = cli.height.unwrap_or(terminal_height) as usize;
 
-    pub fn compute_one_step(&mut self) {
-        // Compute the new state
-        for x_pos in 0..self.width {
-            for y_pos in 0..self.height {
-                let alive_around =
-                    self.count_cells_around_position(x_pos as i32, y_pos as i32, CellState::Alive);
-
-                let index = self.index_by_position(x_pos as i32, y_pos as i32);
-                let cell = self.cells.get_mut(index).unwrap();
-
-                match cell.current {
-                    CellState::Alive => {
-                        cell.next = match alive_around {
-                            // Any live cell with two or three live neighbours survives.
-                            2 | 3 => CellState::Alive,
-                            // Death by {over,under}crowd
-                            _ => CellState::Dead(1),
-                        }
-                    }
-                    CellState::Dead(cycles) => {
-                        // Any dead cell with three live neighbours becomes a live cell.
-                        if alive_around == 3 {
-                            cell.next = CellState::Alive;
-                            continue;
-                        }
-                        cell.next = CellState::Dead(match cycles {
-                            u8::MAX => u8::MAX,
-                            _ => cycles + 1,
-                        })
-                    }
-                };
-            }
-        }
-        // Swap the new and the old states
-        for cell in self.cells.iter_mut() {
-            std::mem::swap(&mut cell.current, &mut cell.next);
-        }
+    let mut generator = rng_for(&cli);
+
+    if cli.infinite {
+        let board = initial_infinite_board(&cli, width, height, &mut *generator);
+        run_infinite_terminal(&cli, width, height, board, generator);
+    } else {
+        let board = initial_board(&cli, width, height, &mut *generator);
+        run_dense_terminal(&cli, board, generator);
     }
 }
 
-fn main() {
-    let (width, height) = termion::terminal_size().unwrap();
+#[cfg(not(feature = "gui"))]
+fn run_dense_terminal(cli: &Cli, mut board: Board, mut generator: Box<dyn RngCore>) {
+    use termion::input::{MouseTerminal, TermRead};
+    use termion::raw::IntoRawMode;
 
-    let mut generator = rand::thread_rng();
-    let mut board =
-        Board::from_shape(width as usize, height as usize).randomize(0.1, &mut generator);
+    let stdout = std::io::stdout();
+    let mut stdout = MouseTerminal::from(stdout.lock().into_raw_mode().unwrap());
+    let mut events = termion::async_stdin().events();
 
-    let mut stdout = std::io::stdout();
+    let mut paused = false;
+    let mut step_ms = cli.step_duration().as_millis() as u64;
 
     write!(stdout, "{clear}", clear = termion::clear::All).unwrap();
     for x_pos in 0..board.width {
@@ -140,58 +161,144 @@ fn main() {
     }
 
     loop {
-        let mut terminal_commands = String::with_capacity(board.width * board.height);
-        for y_pos in 0..board.height {
-            for x_pos in 0..board.width {
-                let index = board.index_by_position(x_pos as i32, y_pos as i32);
-                let cell = board.cells.get(index).unwrap();
-
-                // Terminals are slooooooooooooow, dont update if possible
-                if cell.current == cell.next {
-                    continue;
+        while let Some(Ok(event)) = events.next() {
+            match controls::command_for_event(event) {
+                Some(Command::Quit) => return,
+                Some(Command::TogglePause) => paused = !paused,
+                Some(Command::Step) if paused => board.compute_one_step(),
+                Some(Command::Step) => {}
+                Some(Command::SpeedUp) => step_ms = step_ms.saturating_sub(5).max(1),
+                Some(Command::SpeedDown) => step_ms += 5,
+                Some(Command::Reseed) => {
+                    board = Board::from_shape(board.width, board.height)
+                        .with_rule(cli.parsed_rule())
+                        .randomize(cli.validated_probability(), &mut *generator);
                 }
+                Some(Command::Clear) => {
+                    board = Board::from_shape(board.width, board.height).with_rule(cli.parsed_rule());
+                }
+                Some(Command::ToggleCell(x, y)) => board.flip_state(x, y),
+                Some(Command::StepBack) | None => {}
+            }
+        }
+
+        if !paused {
+            let mut terminal_commands = String::with_capacity(board.width * board.height);
+            for y_pos in 0..board.height {
+                for x_pos in 0..board.width {
+                    let index = board.index_by_position(x_pos as i32, y_pos as i32);
+                    let cell = board.cells.get(index).unwrap();
+
+                    // Terminals are slooooooooooooow, dont update if possible
+                    if cell.current == cell.next {
+                        continue;
+                    }
 
-                match board.cells.get(index).unwrap().current {
-                    CellState::Alive => terminal_commands.push_str(
+                    let (r, g, b) = cell_color(cell.current);
+                    terminal_commands.push_str(
                         format!(
                             "{}{} ",
                             termion::cursor::Goto((x_pos + 1) as u16, (y_pos + 1) as u16),
-                            termion::color::Bg(termion::color::Rgb(u8::MAX, u8::MAX, u8::MAX,))
+                            termion::color::Bg(termion::color::Rgb(r, g, b))
                         )
                         .as_str(),
-                    ),
-                    CellState::Dead(cycles) => {
-                        let intencity_multiplier: u16 = 20;
-                        let intencity = if cycles as u16 * intencity_multiplier > u8::MAX as u16 {
-                            0
-                        } else {
-                            u8::MAX - cycles * intencity_multiplier as u8
-                        };
-                        terminal_commands.push_str(
-                            format!(
-                                "{}{} ",
-                                termion::cursor::Goto((x_pos + 1) as u16, (y_pos + 1) as u16),
-                                termion::color::Bg(termion::color::Rgb(
-                                    intencity / 2,
-                                    intencity / 5,
-                                    intencity,
-                                )),
-                            )
-                            .as_str(),
-                        )
-                    }
+                    );
+                }
+            }
+
+            write!(
+                stdout,
+                "{terminal_commands}{reset}",
+                reset = termion::color::Bg(termion::color::Black),
+            )
+            .unwrap();
+            board.compute_one_step();
+        }
+
+        std::thread::sleep(std::time::Duration::from_millis(step_ms));
+    }
+}
+
+/// Drives an [`InfiniteBoard`] through a `width`x`height` viewport centered
+/// on the origin. The sparse backend has no per-cell age to fade, so live
+/// cells render as plain white against black rather than through
+/// [`color::cell_color`].
+#[cfg(not(feature = "gui"))]
+fn run_infinite_terminal(
+    cli: &Cli,
+    width: usize,
+    height: usize,
+    mut board: InfiniteBoard,
+    mut generator: Box<dyn RngCore>,
+) {
+    use termion::input::{MouseTerminal, TermRead};
+    use termion::raw::IntoRawMode;
+
+    let stdout = std::io::stdout();
+    let mut stdout = MouseTerminal::from(stdout.lock().into_raw_mode().unwrap());
+    let mut events = termion::async_stdin().events();
+
+    let mut paused = false;
+    let mut step_ms = cli.step_duration().as_millis() as u64;
+    let x_offset = width as i64 / 2;
+    let y_offset = height as i64 / 2;
+
+    write!(stdout, "{clear}", clear = termion::clear::All).unwrap();
+
+    loop {
+        while let Some(Ok(event)) = events.next() {
+            match controls::command_for_event(event) {
+                Some(Command::Quit) => return,
+                Some(Command::TogglePause) => paused = !paused,
+                Some(Command::Step) if paused => board.step_forward(),
+                Some(Command::Step) => {}
+                Some(Command::SpeedUp) => step_ms = step_ms.saturating_sub(5).max(1),
+                Some(Command::SpeedDown) => step_ms += 5,
+                Some(Command::Reseed) => {
+                    board = InfiniteBoard::randomize(
+                        width,
+                        height,
+                        cli.validated_probability(),
+                        &mut *generator,
+                    )
+                    .with_rule(cli.parsed_rule());
+                }
+                Some(Command::Clear) => board.reset(),
+                Some(Command::StepBack) => {
+                    let _ = board.step_backward();
                 }
+                Some(Command::ToggleCell(_, _)) | None => {}
             }
         }
 
-        write!(
-            stdout,
-            "{terminal_commands}{reset}",
-            reset = termion::color::Bg(termion::color::Black),
-        )
-        .unwrap();
-        board.compute_one_step();
+        if !paused {
+            let mut terminal_commands = String::with_capacity(width * height);
+            for y_pos in 0..height as i64 {
+                for x_pos in 0..width as i64 {
+                    let alive = board
+                        .live_cells()
+                        .contains(&(x_pos - x_offset, y_pos - y_offset));
+                    let (r, g, b) = if alive { (u8::MAX, u8::MAX, u8::MAX) } else { (0, 0, 0) };
+                    terminal_commands.push_str(
+                        format!(
+                            "{}{} ",
+                            termion::cursor::Goto((x_pos + 1) as u16, (y_pos + 1) as u16),
+                            termion::color::Bg(termion::color::Rgb(r, g, b))
+                        )
+                        .as_str(),
+                    );
+                }
+            }
+
+            write!(
+                stdout,
+                "{terminal_commands}{reset}",
+                reset = termion::color::Bg(termion::color::Black),
+            )
+            .unwrap();
+            board.step_forward();
+        }
 
-        std::thread::sleep(std::time::Duration::from_millis(10));
+        std::thread::sleep(std::time::Duration::from_millis(step_ms));
     }
 }
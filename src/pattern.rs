@@ -0,0 +1,227 @@
+//! Parsers for the two common ways Life patterns are shared around:
+//! plaintext (`.cells`-style) and run-length-encoded (`.rle`) files.
+//!
+//! Both parsers produce a [`ParsedPattern`], which only knows the pattern's
+//! own bounding box and which of its cells are alive; centering the pattern
+//! onto an actual [`crate::board::Board`] is the board's job.
+
+use crate::rule::Rule;
+
+/// A pattern parsed from a file, not yet placed on a board.
+#[derive(Debug)]
+pub struct ParsedPattern {
+    pub width: usize,
+    pub height: usize,
+    pub live_cells: Vec<(usize, usize)>,
+    /// The rule requested by the file, if any (only `.rle` headers carry one).
+    pub rule: Option<Rule>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatternError {
+    /// A plaintext line used a character that is neither a live nor a dead marker.
+    InvalidChar(char),
+    /// An `.rle` file's body did not end with a `!` terminator.
+    UnterminatedRle,
+    /// An `.rle` header was missing or malformed.
+    InvalidHeader(String),
+    /// The pattern does not fit on a board of the requested shape.
+    TooLarge {
+        pattern_width: usize,
+        pattern_height: usize,
+        board_width: usize,
+        board_height: usize,
+    },
+}
+
+impl std::fmt::Display for PatternError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PatternError::InvalidChar(ch) => write!(f, "invalid pattern character: {ch:?}"),
+            PatternError::UnterminatedRle => write!(f, "RLE pattern is missing its `!` terminator"),
+            PatternError::InvalidHeader(header) => write!(f, "invalid RLE header: {header}"),
+            PatternError::TooLarge {
+                pattern_width,
+                pattern_height,
+                board_width,
+                board_height,
+            } => write!(
+                f,
+                "pattern is {pattern_width}x{pattern_height}, too large for a {board_width}x{board_height} board"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PatternError {}
+
+/// Parses the plaintext format: one line per row, `*`/`O` for a live cell
+/// and `.`/space for a dead one.
+pub fn parse_plaintext(text: &str) -> Result<ParsedPattern, PatternError> {
+    let mut live_cells = Vec::new();
+    let mut width = 0;
+    let mut height = 0;
+
+    for (y, line) in text.lines().enumerate() {
+        width = width.max(line.len());
+        height = y + 1;
+
+        for (x, ch) in line.chars().enumerate() {
+            match ch {
+                '*' | 'O' => live_cells.push((x, y)),
+                '.' | ' ' => {}
+                other => return Err(PatternError::InvalidChar(other)),
+            }
+        }
+    }
+
+    Ok(ParsedPattern {
+        width,
+        height,
+        live_cells,
+        rule: None,
+    })
+}
+
+/// Parses the run-length-encoded `.rle` format: a header line
+/// `x = W, y = H[, rule = B.../S...]`, followed by a body token stream where
+/// `b` is dead, `o` is alive, `$` ends a row and `!` ends the pattern, each
+/// optionally prefixed by a repeat count (e.g. `3o2b$`).
+pub fn parse_rle(text: &str) -> Result<ParsedPattern, PatternError> {
+    let mut lines = text.lines();
+    let header = lines
+        .find(|line| !line.trim_start().starts_with('#') && !line.trim().is_empty())
+        .ok_or_else(|| PatternError::InvalidHeader(String::new()))?;
+
+    let (width, height, rule) = parse_rle_header(header)?;
+
+    let body: String = lines.collect();
+    let (live_cells, terminated) = parse_rle_body(&body)?;
+    if !terminated {
+        return Err(PatternError::UnterminatedRle);
+    }
+
+    Ok(ParsedPattern {
+        width,
+        height,
+        live_cells,
+        rule,
+    })
+}
+
+fn parse_rle_header(header: &str) -> Result<(usize, usize, Option<Rule>), PatternError> {
+    let mut width = None;
+    let mut height = None;
+    let mut rule = None;
+
+    for field in header.split(',') {
+        let (key, value) = field
+            .split_once('=')
+            .ok_or_else(|| PatternError::InvalidHeader(header.to_string()))?;
+        let key = key.trim();
+        let value = value.trim();
+
+        match key {
+            "x" => {
+                width = Some(
+                    value
+                        .parse()
+                        .map_err(|_| PatternError::InvalidHeader(header.to_string()))?,
+                )
+            }
+            "y" => {
+                height = Some(
+                    value
+                        .parse()
+                        .map_err(|_| PatternError::InvalidHeader(header.to_string()))?,
+                )
+            }
+            "rule" => {
+                rule = Some(
+                    value
+                        .parse()
+                        .map_err(|_| PatternError::InvalidHeader(header.to_string()))?,
+                )
+            }
+            _ => {}
+        }
+    }
+
+    match (width, height) {
+        (Some(width), Some(height)) => Ok((width, height, rule)),
+        _ => Err(PatternError::InvalidHeader(header.to_string())),
+    }
+}
+
+fn parse_rle_body(body: &str) -> Result<(Vec<(usize, usize)>, bool), PatternError> {
+    let mut live_cells = Vec::new();
+    let mut x = 0usize;
+    let mut y = 0usize;
+    let mut count = String::new();
+
+    for ch in body.chars() {
+        if ch.is_ascii_digit() {
+            count.push(ch);
+            continue;
+        }
+
+        let repeat: usize = if count.is_empty() {
+            1
+        } else {
+            count.parse().unwrap()
+        };
+        count.clear();
+
+        match ch {
+            'b' => x += repeat,
+            'o' => {
+                for _ in 0..repeat {
+                    live_cells.push((x, y));
+                    x += 1;
+                }
+            }
+            '$' => {
+                y += repeat;
+                x = 0;
+            }
+            '!' => return Ok((live_cells, true)),
+            _ => {}
+        }
+    }
+
+    Ok((live_cells, false))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_plaintext_glider() {
+        let parsed = parse_plaintext(".*.\n..*\n***").unwrap();
+        assert_eq!(parsed.width, 3);
+        assert_eq!(parsed.height, 3);
+        assert_eq!(parsed.live_cells.len(), 5);
+    }
+
+    #[test]
+    fn rejects_invalid_plaintext_char() {
+        assert!(parse_plaintext("abc").is_err());
+    }
+
+    #[test]
+    fn parses_rle_glider() {
+        let text = "x = 3, y = 3, rule = B3/S23\nbo$2bo$3o!";
+        let parsed = parse_rle(text).unwrap();
+        assert_eq!(parsed.width, 3);
+        assert_eq!(parsed.height, 3);
+        assert_eq!(parsed.live_cells.len(), 5);
+        assert!(parsed.rule.is_some());
+    }
+
+    #[test]
+    fn rejects_unterminated_rle() {
+        let text = "x = 3, y = 3\nbo$2bo$3o";
+        assert_eq!(parse_rle(text).unwrap_err(), PatternError::UnterminatedRle);
+    }
+}
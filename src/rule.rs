@@ -0,0 +1,130 @@
+//! Life-like cellular automaton rules in Golly's `B{digits}/S{digits}` notation.
+//!
+//! A `Rule` is nothing more than two sets of neighbor counts: the counts at
+//! which a dead cell is born, and the counts at which a live cell survives.
+//! Conway's Game of Life is `B3/S23`, but the same engine can run HighLife
+//! (`B36/S23`), Seeds (`B2/S`), and any other life-like automaton just by
+//! swapping the rule string.
+
+use std::str::FromStr;
+
+/// A parsed birth/survival rule, stored as two 9-bit masks (bit `n` set
+/// means "applies when `n` neighbors are alive") so membership tests are a
+/// single shift-and-test instead of a `match`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rule {
+    born: u16,
+    survive: u16,
+}
+
+impl Rule {
+    /// The classic Conway's Game of Life rule: B3/S23.
+    pub fn conway() -> Self {
+        Rule {
+            born: digits_to_mask(&[3]),
+            survive: digits_to_mask(&[2, 3]),
+        }
+    }
+
+    pub fn is_born(&self, alive_around: u8) -> bool {
+        mask_contains(self.born, alive_around)
+    }
+
+    pub fn is_survivor(&self, alive_around: u8) -> bool {
+        mask_contains(self.survive, alive_around)
+    }
+}
+
+impl Default for Rule {
+    fn default() -> Self {
+        Rule::conway()
+    }
+}
+
+fn mask_contains(mask: u16, count: u8) -> bool {
+    count <= 8 && (mask & (1 << count)) != 0
+}
+
+fn digits_to_mask(digits: &[u8]) -> u16 {
+    digits.iter().fold(0u16, |mask, &digit| mask | (1 << digit))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleParseError(String);
+
+impl std::fmt::Display for RuleParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid rule string: {}", self.0)
+    }
+}
+
+impl std::error::Error for RuleParseError {}
+
+impl FromStr for Rule {
+    type Err = RuleParseError;
+
+    /// Parses strings shaped like `B3/S23`. Either half may have no digits
+    /// (e.g. `B2/S` for Seeds), but the `B` and `S` letters and the `/`
+    /// separator are mandatory.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let (born_part, survive_part) = text
+            .split_once('/')
+            .ok_or_else(|| RuleParseError(text.to_string()))?;
+
+        let born_digits = born_part
+            .strip_prefix('B')
+            .ok_or_else(|| RuleParseError(text.to_string()))?;
+        let survive_digits = survive_part
+            .strip_prefix('S')
+            .ok_or_else(|| RuleParseError(text.to_string()))?;
+
+        Ok(Rule {
+            born: parse_digit_mask(born_digits, text)?,
+            survive: parse_digit_mask(survive_digits, text)?,
+        })
+    }
+}
+
+fn parse_digit_mask(digits: &str, original: &str) -> Result<u16, RuleParseError> {
+    digits.chars().try_fold(0u16, |mask, ch| {
+        let digit = ch
+            .to_digit(10)
+            .filter(|&d| d <= 8)
+            .ok_or_else(|| RuleParseError(original.to_string()))?;
+        Ok(mask | (1 << digit))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_conway() {
+        let rule: Rule = "B3/S23".parse().unwrap();
+        assert_eq!(rule, Rule::conway());
+    }
+
+    #[test]
+    fn parses_highlife() {
+        let rule: Rule = "B36/S23".parse().unwrap();
+        assert!(rule.is_born(3));
+        assert!(rule.is_born(6));
+        assert!(!rule.is_born(4));
+    }
+
+    #[test]
+    fn parses_empty_half() {
+        let rule: Rule = "B2/S".parse().unwrap();
+        assert!(rule.is_born(2));
+        assert!(!rule.is_survivor(2));
+        assert!(!rule.is_survivor(3));
+    }
+
+    #[test]
+    fn rejects_malformed_strings() {
+        assert!("B3S23".parse::<Rule>().is_err());
+        assert!("X3/S23".parse::<Rule>().is_err());
+        assert!("B9/S23".parse::<Rule>().is_err());
+    }
+}